@@ -1,4 +1,4 @@
-use std::{io::{BufReader, self, BufRead, BufWriter, Write}, fs};
+use std::{collections::{HashMap, HashSet}, io::{BufReader, self, BufRead, BufWriter, Write}, fs};
 use ethabi::ParamType;
 use hex::ToHex;
 use serde::Serialize;
@@ -36,8 +36,11 @@ use ink_lang as ink;
 pub use self::{name}::\{
     {name | capitalize},
     {name | capitalize}Ref,
+    Bytes,
+    CallError,
     FixedBytes,
     H160,
+    I256,
     U256,
 };
 
@@ -49,7 +52,7 @@ const EVM_ID: u8 = {evm_id};
 mod {name} \{
 {{ for function in functions }}
     // Selector for `{function.selector}`
-    const {function.name | upper_snake}_SELECTOR: [u8; 4] = hex!["{function.selector_hash}"];
+    const {function.method_name | upper_snake}_SELECTOR: [u8; 4] = hex!["{function.selector_hash}"];
 {{ endfor }}
 
     use ethabi::Token;
@@ -59,6 +62,37 @@ mod {name} \{
     use scale::\{Encode, Decode};
     use scale_info::TypeInfo;
 
+{{ for struct in structs }}
+    /// Generated from a tuple (ABI-encoder-v2 struct) parameter.
+    #[derive(Debug, Encode, Decode, TypeInfo)]
+    pub struct {struct.name} \{
+        {{ for field in struct.fields }}pub {field.name}: {field.rust_type},
+        {{ endfor }}
+    }
+
+    impl Tokenize for {struct.name} \{
+        fn tokenize(self) -> Token \{
+            Token::Tuple(vec![{{ for field in struct.fields }}self.{field.name}.tokenize(){{ if not @last }}, {{ endif }}{{ endfor }}])
+        }
+    }
+
+    impl FromToken for {struct.name} \{
+        fn from_token(token: Token) -> Result<Self, InvalidOutputType> \{
+            match token \{
+                Token::Tuple(tokens) => \{
+                    let mut tokens = tokens.into_iter();
+
+                    Ok({struct.name} \{
+                        {{ for field in struct.fields }}{field.name}: FromToken::from_token(tokens.next().ok_or_else(|| InvalidOutputType("missing tuple field".to_owned()))?)?,
+                        {{ endfor }}
+                    })
+                },
+                other => Err(InvalidOutputType(format!("expected Tuple, got \{:?}", other))),
+            }
+        }
+    }
+{{ endfor }}
+
     #[ink(storage)]
     pub struct {name | capitalize} \{
         evm_address: H160,
@@ -73,21 +107,33 @@ mod {name} \{
 
 {{ for function in functions }}
         /// Send `{function.name}` call to contract
+        ///
+        /// # Errors
+        ///
+        /// Returns `Err` if the `xvm_call` extension itself fails, or if its
+        /// result bytes don't decode as this function's declared outputs.
         #[ink(message)]
-        pub fn {function.name | snake}(&mut self, {{ for input in function.inputs }}{input.name}: {input.rust_type}{{ if not @last }}, {{ endif }}{{ endfor }}) -> {function.output} \{
-            let encoded_input = Self::{function.name | snake}_encode({{ for input in function.inputs }}{input.name}{{ if not @last }}, {{ endif }}{{ endfor }});
-            self.env()
+        pub fn {function.method_name | snake}(&mut self, {{ for input in function.inputs }}{input.name}: {input.rust_type}{{ if not @last }}, {{ endif }}{{ endfor }}) -> Result<{function.output}, CallError> \{
+            let encoded_input = Self::{function.method_name | snake}_encode({{ for input in function.inputs }}{input.name}{{ if not @last }}, {{ endif }}{{ endfor }});
+            let result = self.env()
                 .extension()
                 .xvm_call(
                     super::EVM_ID,
                     Vec::from(self.evm_address.0.as_ref()),
                     encoded_input,
                 )
-                .is_ok()
+                .map_err(|_| CallError::XvmCall)?;
+
+            let output_types = [
+                {{ for output in function.outputs }}{output.param_type_expr}{{ if not @last }}, {{ endif }}{{ endfor }}
+            ];
+
+            let tokens = ethabi::decode(&output_types, &result).map_err(|_| CallError::Decode)?;
+            Detokenize::from_tokens(tokens).map_err(CallError::InvalidOutput)
         }
 
-        fn {function.name | snake}_encode({{ for input in function.inputs }}{input.name}: {input.rust_type}{{ if not @last }}, {{ endif }}{{ endfor }}) -> Vec<u8> \{
-            let mut encoded = {function.name | upper_snake}_SELECTOR.to_vec();
+        fn {function.method_name | snake}_encode({{ for input in function.inputs }}{input.name}: {input.rust_type}{{ if not @last }}, {{ endif }}{{ endfor }}) -> Vec<u8> \{
+            let mut encoded = {function.method_name | upper_snake}_SELECTOR.to_vec();
             let input = [
                 {{ for input in function.inputs }}{input.name}.tokenize(){{ if not @last }},
                 {{ endif }}{{ endfor }}
@@ -107,6 +153,13 @@ mod {name} \{
     #[derive(Debug, Encode, Decode, TypeInfo)]
     pub struct U256([u8; 32]);
 
+    /// Custom wrapper to make `I256` scale-encodable. Stored as the same
+    /// 32-byte two's-complement big-endian representation `ethabi` uses for
+    /// `Token::Int`, so conversions to/from `ethereum_types::U256` are a
+    /// direct bit copy.
+    #[derive(Debug, Encode, Decode, TypeInfo)]
+    pub struct I256([u8; 32]);
+
     impl From<[u8; 20]> for H160 \{
         fn from(other: [u8; 20]) -> Self \{
             H160(other)
@@ -143,6 +196,37 @@ mod {name} \{
         }
     }
 
+    impl From<[u8; 32]> for I256 \{
+        fn from(other: [u8; 32]) -> Self \{
+            I256(other)
+        }
+    }
+
+    impl From<i128> for I256 \{
+        fn from(other: i128) -> Self \{
+            // Sign-extend the 128-bit value out to the full 32-byte width so
+            // negative numbers keep their two's-complement representation.
+            let extension = if other.is_negative() \{ 0xFFu8 } else \{ 0x00u8 };
+
+            let mut bytes = [extension; 32];
+            bytes[16..].copy_from_slice(&other.to_be_bytes());
+
+            I256(bytes)
+        }
+    }
+
+    impl From<ethabi::ethereum_types::U256> for I256 \{
+        fn from(other: ethabi::ethereum_types::U256) -> Self \{
+            I256(other.into())
+        }
+    }
+
+    impl Into<ethabi::ethereum_types::U256> for I256 \{
+        fn into(self) -> ethabi::ethereum_types::U256 \{
+            ethabi::ethereum_types::U256::from(self.0)
+        }
+    }
+
     /// Helper trait used to convert Rust types to their serializable `Token` counterparts.
     /// Should be 100% inlined and therefore should not negatively affect smart contract size.
     trait Tokenize \{
@@ -163,6 +247,7 @@ mod {name} \{
 
     /// Rust currently lacks specialization, thus overlapping trait implementations are forbidden.
     /// We use this newtype wrapper to provide custom tokenize implementation for byte arrays.
+    #[derive(Debug, Encode, Decode, TypeInfo)]
     pub struct FixedBytes<const N: usize>(pub [u8; N]);
 
     impl<const N: usize> From<[u8; N]> for FixedBytes<N> \{
@@ -183,6 +268,30 @@ mod {name} \{
         }
     }
 
+    /// Dynamic `bytes`, kept distinct from `Vec<u8>` (which is what `uint8[]`
+    /// maps to) so the two tokenize unambiguously: `bytes` must produce
+    /// `Token::Bytes`, while `uint8[]` must produce `Token::Array(vec![Token::Uint, ...])`.
+    #[derive(Debug, Encode, Decode, TypeInfo)]
+    pub struct Bytes(pub Vec<u8>);
+
+    impl From<Vec<u8>> for Bytes \{
+        fn from(other: Vec<u8>) -> Self \{
+            Bytes(other)
+        }
+    }
+
+    impl Into<Vec<u8>> for Bytes \{
+        fn into(self) -> Vec<u8> \{
+            self.0
+        }
+    }
+
+    impl Tokenize for Bytes \{
+        fn tokenize(self) -> Token \{
+            Token::Bytes(self.0)
+        }
+    }
+
     macro_rules! tokenize_tuple \{
         ($($i:ident),+) => \{
             impl<$($i: Tokenize,)+> Tokenize for ($($i,)+) \{
@@ -220,7 +329,10 @@ mod {name} \{
             $(
                 impl Tokenize for $t \{
                     fn tokenize(self) -> Token \{
-                        Token::Int(self.into())
+                        // `ethereum_types::U256::from` panics on a negative primitive, so
+                        // go through `I256`'s two's-complement sign extension instead of
+                        // converting straight into the unsigned `U256` `Token::Int` wants.
+                        Token::Int(I256::from(self as i128).into())
                     }
                 }
             )+
@@ -253,6 +365,208 @@ mod {name} \{
             Token::Uint(ethabi::ethereum_types::U256::from(self.0))
         }
     }
+
+    impl Tokenize for I256 \{
+        fn tokenize(self) -> Token \{
+            Token::Int(ethabi::ethereum_types::U256::from(self.0))
+        }
+    }
+
+    /// Error produced when a decoded `Token` does not match the shape a generated type expects.
+    #[derive(Debug)]
+    pub struct InvalidOutputType(pub String);
+
+    /// Error returned by a generated message when the underlying `xvm_call`
+    /// fails, or when it succeeds but its return bytes don't decode as the
+    /// message's declared output type.
+    #[derive(Debug)]
+    pub enum CallError \{
+        /// The `xvm_call` chain extension itself returned an error.
+        XvmCall,
+        /// The call succeeded, but its return bytes don't match the ABI output types.
+        Decode,
+        /// The decoded tokens don't match the generated output type.
+        InvalidOutput(InvalidOutputType),
+    }
+
+    /// Inverse of `Tokenize` for a single `Token`. Used by `Detokenize` to rebuild
+    /// leaf return values out of the tokens `ethabi::decode` hands back.
+    trait FromToken: Sized \{
+        fn from_token(token: Token) -> Result<Self, InvalidOutputType>;
+    }
+
+    /// Helper trait used to convert the `Token`s returned by `ethabi::decode` back
+    /// into the generated method's Rust return type. Mirrors ethers-rs's `Detokenize`.
+    trait Detokenize: Sized \{
+        fn from_tokens(tokens: Vec<Token>) -> Result<Self, InvalidOutputType>;
+    }
+
+    impl<T: FromToken> Detokenize for T \{
+        fn from_tokens(mut tokens: Vec<Token>) -> Result<Self, InvalidOutputType> \{
+            if tokens.len() != 1 \{
+                return Err(InvalidOutputType(format!("expected a single return value, got \{}", tokens.len())));
+            }
+
+            T::from_token(tokens.remove(0))
+        }
+    }
+
+    impl Detokenize for () \{
+        fn from_tokens(tokens: Vec<Token>) -> Result<Self, InvalidOutputType> \{
+            if !tokens.is_empty() \{
+                return Err(InvalidOutputType(format!("expected no return values, got \{}", tokens.len())));
+            }
+
+            Ok(())
+        }
+    }
+
+    macro_rules! detokenize_tuple \{
+        ($len:expr; $($i:ident),+) => \{
+            impl<$($i: FromToken,)+> Detokenize for ($($i,)+) \{
+                fn from_tokens(mut tokens: Vec<Token>) -> Result<Self, InvalidOutputType> \{
+                    if tokens.len() != $len \{
+                        return Err(InvalidOutputType(format!("expected \{} return values, got \{}", $len, tokens.len())));
+                    }
+
+                    tokens.reverse();
+
+                    #[allow(non_snake_case)]
+                    Ok(($($i::from_token(tokens.pop().unwrap())?,)+))
+                }
+            }
+        };
+    }
+
+    detokenize_tuple!(2; A, B);
+    detokenize_tuple!(3; A, B, C);
+    detokenize_tuple!(4; A, B, C, D);
+    detokenize_tuple!(5; A, B, C, D, E);
+    detokenize_tuple!(6; A, B, C, D, E, F);
+    detokenize_tuple!(7; A, B, C, D, E, F, G);
+    detokenize_tuple!(8; A, B, C, D, E, F, G, H);
+
+    macro_rules! detokenize_ints \{
+        (unsigned: $($t:ty),+) => \{
+            $(
+                impl FromToken for $t \{
+                    fn from_token(token: Token) -> Result<Self, InvalidOutputType> \{
+                        match token \{
+                            Token::Uint(u) => Ok(u.low_u128() as $t),
+                            other => Err(InvalidOutputType(format!("expected Uint, got \{:?}", other))),
+                        }
+                    }
+                }
+            )+
+        };
+
+        (signed: $($t:ty),+) => \{
+            $(
+                impl FromToken for $t \{
+                    fn from_token(token: Token) -> Result<Self, InvalidOutputType> \{
+                        match token \{
+                            Token::Int(i) => Ok(i.low_u128() as i128 as $t),
+                            other => Err(InvalidOutputType(format!("expected Int, got \{:?}", other))),
+                        }
+                    }
+                }
+            )+
+        };
+    }
+
+    detokenize_ints!(signed: i8, i16, i32, i64, i128);
+    detokenize_ints!(unsigned: u8, u16, u32, u64, u128);
+
+    impl FromToken for H160 \{
+        fn from_token(token: Token) -> Result<Self, InvalidOutputType> \{
+            match token \{
+                Token::Address(address) => Ok(H160::from(address)),
+                other => Err(InvalidOutputType(format!("expected Address, got \{:?}", other))),
+            }
+        }
+    }
+
+    impl FromToken for bool \{
+        fn from_token(token: Token) -> Result<Self, InvalidOutputType> \{
+            match token \{
+                Token::Bool(b) => Ok(b),
+                other => Err(InvalidOutputType(format!("expected Bool, got \{:?}", other))),
+            }
+        }
+    }
+
+    impl FromToken for String \{
+        fn from_token(token: Token) -> Result<Self, InvalidOutputType> \{
+            match token \{
+                Token::String(s) => Ok(s),
+                other => Err(InvalidOutputType(format!("expected String, got \{:?}", other))),
+            }
+        }
+    }
+
+    impl FromToken for U256 \{
+        fn from_token(token: Token) -> Result<Self, InvalidOutputType> \{
+            match token \{
+                Token::Uint(u) => Ok(U256::from(u)),
+                other => Err(InvalidOutputType(format!("expected Uint, got \{:?}", other))),
+            }
+        }
+    }
+
+    impl FromToken for I256 \{
+        fn from_token(token: Token) -> Result<Self, InvalidOutputType> \{
+            match token \{
+                Token::Int(i) => Ok(I256::from(i)),
+                other => Err(InvalidOutputType(format!("expected Int, got \{:?}", other))),
+            }
+        }
+    }
+
+    impl<const N: usize> FromToken for FixedBytes<N> \{
+        fn from_token(token: Token) -> Result<Self, InvalidOutputType> \{
+            match token \{
+                Token::FixedBytes(bytes) => \{
+                    let bytes: [u8; N] = bytes.try_into()
+                        .map_err(|_| InvalidOutputType("unexpected FixedBytes length".to_owned()))?;
+
+                    Ok(FixedBytes(bytes))
+                },
+                other => Err(InvalidOutputType(format!("expected FixedBytes, got \{:?}", other))),
+            }
+        }
+    }
+
+    impl FromToken for Bytes \{
+        fn from_token(token: Token) -> Result<Self, InvalidOutputType> \{
+            match token \{
+                Token::Bytes(bytes) => Ok(Bytes(bytes)),
+                other => Err(InvalidOutputType(format!("expected Bytes, got \{:?}", other))),
+            }
+        }
+    }
+
+    impl<T: FromToken> FromToken for Vec<T> \{
+        fn from_token(token: Token) -> Result<Self, InvalidOutputType> \{
+            match token \{
+                Token::Array(tokens) => tokens.into_iter().map(T::from_token).collect(),
+                other => Err(InvalidOutputType(format!("expected Array, got \{:?}", other))),
+            }
+        }
+    }
+
+    impl<T: FromToken, const N: usize> FromToken for [T; N] \{
+        fn from_token(token: Token) -> Result<Self, InvalidOutputType> \{
+            match token \{
+                Token::FixedArray(tokens) => \{
+                    let values: Vec<T> = tokens.into_iter().map(T::from_token).collect::<Result<_, _>>()?;
+
+                    values.try_into()
+                        .map_err(|_| InvalidOutputType("unexpected FixedArray length".to_owned()))
+                },
+                other => Err(InvalidOutputType(format!("expected FixedArray, got \{:?}", other))),
+            }
+        }
+    }
 }
 "#;
 
@@ -267,19 +581,53 @@ struct Input {
     rust_type: String,
 }
 
+#[derive(Serialize)]
+struct Output {
+    // Type came from metadata
+    evm_type: String,
+
+    // Equivalent type to use in ink! code
+    rust_type: String,
+
+    // Rust expression that reconstructs this output's exact `ethabi::ParamType`
+    // (including tuple component types) for use when decoding `xvm_call` results.
+    param_type_expr: String,
+}
+
 #[derive(Serialize)]
 struct Function {
     name: String,
+
+    // Resolved Rust identifier for this function: equal to `name` unless an
+    // overload shares it, in which case a disambiguating suffix is appended.
+    method_name: String,
+
     inputs: Vec<Input>,
+    outputs: Vec<Output>,
     output: String,
     selector: String,
     selector_hash: String,
 }
 
+#[derive(Serialize, PartialEq, Debug)]
+struct StructField {
+    name: String,
+
+    // Equivalent type to use in ink! code
+    rust_type: String,
+}
+
+#[derive(Serialize)]
+struct GeneratedStruct {
+    name: String,
+    fields: Vec<StructField>,
+}
+
 #[derive(Serialize)]
 struct Module {
     name: String,
     evm_id: String,
+    structs: Vec<GeneratedStruct>,
     functions: Vec<Function>,
 }
 
@@ -289,9 +637,15 @@ fn convert_type(ty: &ParamType) -> String {
         ParamType::Address => "H160".to_owned(),
         ParamType::Array(inner) => format!("Vec<{}>", convert_type(inner)),
         ParamType::FixedArray(inner, size) => format!("[{}; {}]", convert_type(inner), size),
-        ParamType::Tuple(inner) => format!("({})", inner.iter().map(convert_type).join(", ")),
+
+        // Tuples need their ABI `components` (field names/types) to become a
+        // readable, named Rust type; `convert_type` only sees a bare
+        // `ParamType` and has no access to those, so every tuple is routed
+        // through `convert_component_type` instead, which does.
+        ParamType::Tuple(_) => unreachable!("tuple ParamTypes must be converted via convert_component_type"),
+
         ParamType::FixedBytes(size) => format!("FixedBytes<{}>", size),
-        ParamType::Bytes => "Vec<u8>".to_owned(),
+        ParamType::Bytes => "Bytes".to_owned(),
         ParamType::String => "String".to_owned(),
 
         ParamType::Int(size) => match size {
@@ -316,84 +670,201 @@ fn convert_type(ty: &ParamType) -> String {
     }
 }
 
-fn main() -> Result<(), String> {
-    let args = Args::parse();
+/// Like `convert_type`, but ABI-encoder-v2 aware: a bare `"tuple"` (optionally
+/// wrapped in array suffixes) carries its field names/types in the ABI's
+/// `components`, so rather than collapsing to an anonymous Rust tuple, this
+/// emits a dedicated named struct into `structs` and returns its name.
+fn convert_component_type(
+    raw_type: &str,
+    components: &json::JsonValue,
+    hint_name: &str,
+    structs: &mut Vec<GeneratedStruct>,
+) -> String {
+    if let Some(inner) = raw_type.strip_suffix("[]") {
+        return format!("Vec<{}>", convert_component_type(inner, components, hint_name, structs));
+    }
 
-    let mut reader: Box<dyn BufRead> = match args.input {
-        Some(filename) => Box::new(BufReader::new(fs::File::open(filename).map_err(|e| e.to_string())?)),
-        None => Box::new(BufReader::new(io::stdin())),
-    };
+    if let Some(rest) = raw_type.strip_suffix(']') {
+        if let Some(bracket) = rest.rfind('[') {
+            let (inner, size) = (&rest[..bracket], &rest[bracket + 1..]);
+            return format!("[{}; {}]", convert_component_type(inner, components, hint_name, structs), size);
+        }
+    }
 
-    let mut writer: Box<dyn Write> = match args.output {
-        Some(filename) => Box::new(BufWriter::new(fs::File::create(filename).map_err(|e| e.to_string())?)),
-        None => Box::new(BufWriter::new(io::stdout())),
+    if raw_type != "tuple" {
+        let param_type = ethabi::param_type::Reader::read(raw_type).unwrap();
+        return convert_type(&param_type);
+    }
+
+    let fields: Vec<_> = components.members().map(|component| {
+        let field_name = component["name"].to_string();
+        let field_type = component["type"].as_str().unwrap();
+
+        let rust_type = convert_component_type(field_type, &component["components"], &field_name, structs);
+
+        StructField {
+            name: field_name,
+            rust_type,
+        }
+    }).collect();
+
+    // Different tuple parameters can carry the exact same shape (e.g. the
+    // same struct used as both an input and an output) - reuse the existing
+    // struct rather than emitting a second, conflicting definition.
+    if let Some(existing) = structs.iter().find(|s| s.fields == fields) {
+        return existing.name.clone();
+    }
+
+    let base_name = if hint_name.is_empty() {
+        "Struct".to_owned()
+    } else {
+        hint_name.to_case(Case::Pascal)
     };
 
-    let mut buf = String::new();
-    reader.read_to_string(&mut buf).map_err(|e| e.to_string())?;
+    // Two unrelated tuples can still land on the same hinted name with a
+    // different shape - keep appending a suffix until the name is free.
+    let mut struct_name = base_name.clone();
+    let mut suffix = 1;
 
-    let parsed = json::parse(&buf).map_err(|e| e.to_string())?;
+    while structs.iter().any(|s| s.name == struct_name) {
+        suffix += 1;
+        struct_name = format!("{}{}", base_name, suffix);
+    }
 
-    let mut template = TinyTemplate::new();
-    template.set_default_formatter(&format_unescaped);
+    structs.push(GeneratedStruct { name: struct_name.clone(), fields });
 
-    template.add_template("module", MODULE_TEMPLATE).map_err(|e| e.to_string())?;
+    struct_name
+}
 
-    template.add_formatter("snake", |value, buf| match value {
-        serde_json::Value::String(s) => { *buf += &s.to_case(Case::Snake); Ok(()) },
-        _ => Err(tinytemplate::error::Error::GenericError { msg: "string value expected".to_owned() }),
-    });
+/// Rebuilds the exact `ethabi::ParamType` for a JSON ABI parameter as the Rust
+/// expression that constructs it. Used instead of re-parsing `evm_type`
+/// through `ethabi::param_type::Reader` when decoding, since a bare `"tuple"`
+/// string carries no component types for `Reader` to recover.
+fn param_type_expr_from_json(raw_type: &str, components: &json::JsonValue) -> String {
+    if let Some(inner) = raw_type.strip_suffix("[]") {
+        return format!("ethabi::ParamType::Array(Box::new({}))", param_type_expr_from_json(inner, components));
+    }
 
-    template.add_formatter("upper_snake", |value, buf| match value {
-        serde_json::Value::String(s) => { *buf += &s.to_case(Case::UpperSnake); Ok(()) },
-        _ => Err(tinytemplate::error::Error::GenericError { msg: "string value expected".to_owned() }),
-    });
+    if let Some(rest) = raw_type.strip_suffix(']') {
+        if let Some(bracket) = rest.rfind('[') {
+            let (inner, size) = (&rest[..bracket], &rest[bracket + 1..]);
+            return format!("ethabi::ParamType::FixedArray(Box::new({}), {})", param_type_expr_from_json(inner, components), size);
+        }
+    }
 
-    template.add_formatter("capitalize", |value, buf| match value {
-        serde_json::Value::String(s) => {
-            let (head, tail) = s.split_at(1);
+    if raw_type != "tuple" {
+        return param_type_expr(&ethabi::param_type::Reader::read(raw_type).unwrap());
+    }
 
-            *buf += &head.to_uppercase();
-            *buf += tail;
+    let fields = components.members()
+        .map(|component| param_type_expr_from_json(component["type"].as_str().unwrap(), &component["components"]))
+        .join(", ");
 
-            Ok(())
-        },
-        _ => Err(tinytemplate::error::Error::GenericError { msg: "string value expected".to_owned() }),
-    });
+    format!("ethabi::ParamType::Tuple(vec![{}])", fields)
+}
 
-    template.add_formatter("convert_type", |value, buf| match value {
-        serde_json::Value::String(raw_type) => {
-            let param_type = ethabi::param_type::Reader::read(raw_type).unwrap();
-            let converted = convert_type(&param_type);
+/// Renders an already-parsed elementary (non-tuple) `ParamType` back into the
+/// Rust expression that constructs it.
+fn param_type_expr(ty: &ParamType) -> String {
+    match ty {
+        ParamType::Bool => "ethabi::ParamType::Bool".to_owned(),
+        ParamType::Address => "ethabi::ParamType::Address".to_owned(),
+        ParamType::Bytes => "ethabi::ParamType::Bytes".to_owned(),
+        ParamType::String => "ethabi::ParamType::String".to_owned(),
+        ParamType::Int(size) => format!("ethabi::ParamType::Int({})", size),
+        ParamType::Uint(size) => format!("ethabi::ParamType::Uint({})", size),
+        ParamType::FixedBytes(size) => format!("ethabi::ParamType::FixedBytes({})", size),
+        ParamType::Array(inner) => format!("ethabi::ParamType::Array(Box::new({}))", param_type_expr(inner)),
+        ParamType::FixedArray(inner, size) => format!("ethabi::ParamType::FixedArray(Box::new({}), {})", param_type_expr(inner), size),
+        ParamType::Tuple(inner) => format!("ethabi::ParamType::Tuple(vec![{}])", inner.iter().map(param_type_expr).join(", ")),
+    }
+}
 
-            buf.push_str(&converted);
-            Ok(())
-        },
+/// Builds the Rust return type for a function's `outputs`: `()` for none, the
+/// bare type for one, or a tuple for more than one.
+fn convert_output_type(outputs: &[Output]) -> String {
+    match outputs {
+        [] => "()".to_owned(),
+        [output] => output.rust_type.clone(),
+        outputs => format!("({})", outputs.iter().map(|output| output.rust_type.as_str()).join(", ")),
+    }
+}
 
-        _ => Err(tinytemplate::error::Error::GenericError { msg: "string value expected".to_owned() }),
-    });
+/// Solidity allows overloaded functions that share a name but differ in
+/// parameters, each with its own 4-byte selector. Rust methods can't overload
+/// like that, so any `name` shared by more than one `Function` gets a stable
+/// `1`, `2`, ... suffix appended to its `method_name`, mirroring how
+/// ethers-rs's abigen resolves overload aliases.
+fn disambiguate_overloads(functions: &mut [Function]) {
+    let mut indices_by_name: HashMap<String, Vec<usize>> = HashMap::new();
+
+    for (index, function) in functions.iter().enumerate() {
+        indices_by_name.entry(function.name.clone()).or_default().push(index);
+    }
+
+    // A `1`/`2`/... suffix could still collide with another, distinctly-named
+    // function in the same ABI (e.g. overloads of `transfer` next to a
+    // genuine `transfer1`), so track every name already in use and skip past
+    // any suffix that's taken rather than assigning it blindly.
+    let mut taken_names: HashSet<String> = functions.iter().map(|function| function.name.clone()).collect();
+
+    for indices in indices_by_name.into_values() {
+        if indices.len() < 2 {
+            continue;
+        }
+
+        let mut suffix = 1;
+
+        for index in indices {
+            let mut candidate = format!("{}{}", functions[index].name, suffix);
+
+            while taken_names.contains(&candidate) {
+                suffix += 1;
+                candidate = format!("{}{}", functions[index].name, suffix);
+            }
+
+            taken_names.insert(candidate.clone());
+            functions[index].method_name = candidate;
+            suffix += 1;
+        }
+    }
+}
+
+/// Parse a JSON ABI into the `Module` the template renders, assigning
+/// generated struct names and disambiguating any overloaded function names.
+fn build_module(parsed: &json::JsonValue, module_name: String, evm_id: String) -> Module {
+    let mut structs: Vec<GeneratedStruct> = Vec::new();
 
-    let functions: Vec<_> = parsed
+    let mut functions: Vec<_> = parsed
         .members()
         .filter(|item| item["type"] == "function" )
-        .filter(|item| item["stateMutability"] != "view" )
-        .filter(|item| item["outputs"].members().all(|output| output["type"] == "bool"))
         .map(|function| {
             let function_name = function["name"].to_string();
 
             let inputs: Vec<_> = function["inputs"].members().map(|m| {
                 let raw_type = m["type"].as_str().unwrap();
-                let param_type = ethabi::param_type::Reader::read(raw_type).unwrap();
-                let converted = convert_type(&param_type);
+                let name = m["name"].to_string();
+                let converted = convert_component_type(raw_type, &m["components"], &name, &mut structs);
 
                 Input {
-                    name: m["name"].to_string(),
+                    name,
                     evm_type: raw_type.to_string(),
                     rust_type: converted,
                 }
             }).collect();
 
-            // let outputs: String = function["outputs"].members().map(|m| format!("{}: {}, ", m["name"], m["type"])).collect();
+            let outputs: Vec<_> = function["outputs"].members().map(|m| {
+                let raw_type = m["type"].as_str().unwrap();
+                let name = m["name"].to_string();
+                let converted = convert_component_type(raw_type, &m["components"], &name, &mut structs);
+                let param_type_expr = param_type_expr_from_json(raw_type, &m["components"]);
+
+                Output {
+                    evm_type: raw_type.to_string(),
+                    rust_type: converted,
+                    param_type_expr,
+                }
+            }).collect();
 
             let selector = format!("{name}({args})",
                 name = function_name,
@@ -405,24 +876,266 @@ fn main() -> Result<(), String> {
             let selector_hash: &[u8] = &hasher.finalize();
             let selector_hash: [u8; 4] = selector_hash[0..=3].try_into().unwrap();
 
+            let output = convert_output_type(&outputs);
+
             Function {
+                method_name: function_name.clone(),
                 name: function_name,
                 inputs,
-                output: "bool".to_owned(),
+                outputs,
+                output,
                 selector,
                 selector_hash: selector_hash.encode_hex(),
             }
         })
         .collect();
 
-    let module = Module {
-        name: args.module_name,
-        evm_id: args.evm_id,
+    disambiguate_overloads(&mut functions);
+
+    Module {
+        name: module_name,
+        evm_id,
+        structs,
         functions,
+    }
+}
+
+/// Render a `Module` through `MODULE_TEMPLATE`, producing the final ink! source.
+fn render_module(module: &Module) -> Result<String, String> {
+    let mut template = TinyTemplate::new();
+    template.set_default_formatter(&format_unescaped);
+
+    template.add_template("module", MODULE_TEMPLATE).map_err(|e| e.to_string())?;
+
+    template.add_formatter("snake", |value, buf| match value {
+        serde_json::Value::String(s) => { *buf += &s.to_case(Case::Snake); Ok(()) },
+        _ => Err(tinytemplate::error::Error::GenericError { msg: "string value expected".to_owned() }),
+    });
+
+    template.add_formatter("upper_snake", |value, buf| match value {
+        serde_json::Value::String(s) => { *buf += &s.to_case(Case::UpperSnake); Ok(()) },
+        _ => Err(tinytemplate::error::Error::GenericError { msg: "string value expected".to_owned() }),
+    });
+
+    template.add_formatter("capitalize", |value, buf| match value {
+        serde_json::Value::String(s) => {
+            let (head, tail) = s.split_at(1);
+
+            *buf += &head.to_uppercase();
+            *buf += tail;
+
+            Ok(())
+        },
+        _ => Err(tinytemplate::error::Error::GenericError { msg: "string value expected".to_owned() }),
+    });
+
+    template.add_formatter("convert_type", |value, buf| match value {
+        serde_json::Value::String(raw_type) => {
+            let param_type = ethabi::param_type::Reader::read(raw_type).unwrap();
+            let converted = convert_type(&param_type);
+
+            buf.push_str(&converted);
+            Ok(())
+        },
+
+        _ => Err(tinytemplate::error::Error::GenericError { msg: "string value expected".to_owned() }),
+    });
+
+    template.render("module", module).map_err(|e| e.to_string())
+}
+
+fn main() -> Result<(), String> {
+    let args = Args::parse();
+
+    let mut reader: Box<dyn BufRead> = match args.input {
+        Some(filename) => Box::new(BufReader::new(fs::File::open(filename).map_err(|e| e.to_string())?)),
+        None => Box::new(BufReader::new(io::stdin())),
+    };
+
+    let mut writer: Box<dyn Write> = match args.output {
+        Some(filename) => Box::new(BufWriter::new(fs::File::create(filename).map_err(|e| e.to_string())?)),
+        None => Box::new(BufWriter::new(io::stdout())),
     };
 
-    let rendered = template.render("module", &module).map_err(|e| e.to_string())?;
+    let mut buf = String::new();
+    reader.read_to_string(&mut buf).map_err(|e| e.to_string())?;
+
+    let parsed = json::parse(&buf).map_err(|e| e.to_string())?;
+    let module = build_module(&parsed, args.module_name, args.evm_id);
+    let rendered = render_module(&module)?;
+
     write!(writer, "{}\n", rendered).map_err(|e| e.to_string())?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_and_uint8_array_convert_to_distinct_rust_types() {
+        assert_eq!(convert_type(&ParamType::Bytes), "Bytes");
+        assert_eq!(convert_type(&ParamType::Array(Box::new(ParamType::Uint(8)))), "Vec<u8>");
+    }
+
+    #[test]
+    fn bytes_and_uint8_array_round_trip_to_distinct_abi_signatures() {
+        let bytes_signature = ethabi::param_type::Writer::write(&ParamType::Bytes);
+        let uint8_array_signature = ethabi::param_type::Writer::write(&ParamType::Array(Box::new(ParamType::Uint(8))));
+
+        assert_eq!(bytes_signature, "bytes");
+        assert_eq!(uint8_array_signature, "uint8[]");
+        assert_ne!(bytes_signature, uint8_array_signature);
+
+        assert_eq!(ethabi::param_type::Reader::read(&bytes_signature).unwrap(), ParamType::Bytes);
+        assert_eq!(ethabi::param_type::Reader::read(&uint8_array_signature).unwrap(), ParamType::Array(Box::new(ParamType::Uint(8))));
+    }
+
+    fn stub_function(name: &str) -> Function {
+        Function {
+            name: name.to_owned(),
+            method_name: name.to_owned(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            output: "()".to_owned(),
+            selector: String::new(),
+            selector_hash: String::new(),
+        }
+    }
+
+    #[test]
+    fn disambiguate_overloads_suffixes_repeated_names() {
+        let mut functions = vec![stub_function("transfer"), stub_function("transfer"), stub_function("balanceOf")];
+
+        disambiguate_overloads(&mut functions);
+
+        assert_eq!(functions[0].method_name, "transfer1");
+        assert_eq!(functions[1].method_name, "transfer2");
+        assert_eq!(functions[2].method_name, "balanceOf");
+    }
+
+    #[test]
+    fn disambiguate_overloads_skips_suffixes_taken_by_other_functions() {
+        // A genuine `transfer1` function sits alongside two `transfer` overloads;
+        // the overloads must not be renamed to something that collides with it.
+        let mut functions = vec![stub_function("transfer"), stub_function("transfer"), stub_function("transfer1")];
+
+        disambiguate_overloads(&mut functions);
+
+        assert_eq!(functions[0].method_name, "transfer2");
+        assert_eq!(functions[1].method_name, "transfer3");
+        assert_eq!(functions[2].method_name, "transfer1");
+    }
+
+    #[test]
+    fn convert_component_type_generates_named_struct_from_tuple_components() {
+        let components = json::parse(r#"[
+            {"name": "x", "type": "uint256", "components": []},
+            {"name": "y", "type": "uint256", "components": []}
+        ]"#).unwrap();
+
+        let mut structs = Vec::new();
+        let rust_type = convert_component_type("tuple", &components, "Point", &mut structs);
+
+        assert_eq!(rust_type, "Point");
+        assert_eq!(structs.len(), 1);
+        assert_eq!(structs[0].name, "Point");
+        assert_eq!(structs[0].fields, vec![
+            StructField { name: "x".to_owned(), rust_type: "U256".to_owned() },
+            StructField { name: "y".to_owned(), rust_type: "U256".to_owned() },
+        ]);
+    }
+
+    #[test]
+    fn convert_component_type_reuses_struct_with_identical_shape() {
+        let components = json::parse(r#"[{"name": "x", "type": "uint256", "components": []}]"#).unwrap();
+        let mut structs = Vec::new();
+
+        let first = convert_component_type("tuple", &components, "Order", &mut structs);
+        let second = convert_component_type("tuple", &components, "DifferentHintSameShape", &mut structs);
+
+        assert_eq!(first, "Order");
+        assert_eq!(second, "Order");
+        assert_eq!(structs.len(), 1);
+    }
+
+    #[test]
+    fn convert_component_type_disambiguates_name_collision_with_different_shape() {
+        let first_components = json::parse(r#"[{"name": "x", "type": "uint256", "components": []}]"#).unwrap();
+        let second_components = json::parse(r#"[{"name": "y", "type": "bool", "components": []}]"#).unwrap();
+        let mut structs = Vec::new();
+
+        let first = convert_component_type("tuple", &first_components, "Order", &mut structs);
+        let second = convert_component_type("tuple", &second_components, "Order", &mut structs);
+
+        assert_eq!(first, "Order");
+        assert_eq!(second, "Order2");
+        assert_eq!(structs.len(), 2);
+    }
+
+    #[test]
+    fn param_type_expr_from_json_reconstructs_nested_tuple_param_type() {
+        let components = json::parse(r#"[
+            {"name": "x", "type": "uint256", "components": []},
+            {"name": "inner", "type": "tuple", "components": [
+                {"name": "flag", "type": "bool", "components": []}
+            ]}
+        ]"#).unwrap();
+
+        let expr = param_type_expr_from_json("tuple", &components);
+
+        assert_eq!(
+            expr,
+            "ethabi::ParamType::Tuple(vec![ethabi::ParamType::Uint(256), ethabi::ParamType::Tuple(vec![ethabi::ParamType::Bool])])",
+        );
+    }
+
+    #[test]
+    fn param_type_expr_from_json_handles_array_of_tuples() {
+        let components = json::parse(r#"[{"name": "x", "type": "uint256", "components": []}]"#).unwrap();
+
+        let expr = param_type_expr_from_json("tuple[]", &components);
+
+        assert_eq!(
+            expr,
+            "ethabi::ParamType::Array(Box::new(ethabi::ParamType::Tuple(vec![ethabi::ParamType::Uint(256)])))",
+        );
+    }
+
+    #[test]
+    fn build_module_renders_end_to_end_without_template_errors() {
+        let abi = json::parse(r#"[
+            {
+                "type": "function",
+                "name": "transfer",
+                "inputs": [
+                    {"name": "to", "type": "address", "components": []},
+                    {"name": "order", "type": "tuple", "components": [
+                        {"name": "amount", "type": "uint256", "components": []},
+                        {"name": "data", "type": "bytes", "components": []}
+                    ]}
+                ],
+                "outputs": [{"name": "", "type": "bool", "components": []}]
+            },
+            {
+                "type": "function",
+                "name": "transfer",
+                "inputs": [{"name": "to", "type": "address", "components": []}],
+                "outputs": [{"name": "", "type": "bool", "components": []}]
+            }
+        ]"#).unwrap();
+
+        let module = build_module(&abi, "erc20".to_owned(), "1".to_owned());
+        let rendered = render_module(&module).expect("template should render a representative ABI");
+
+        // The two `transfer` overloads must have been disambiguated, the
+        // tuple input must have become a named struct, and the failure path
+        // of the generated message must surface as a `Result`, not a panic.
+        assert!(rendered.contains("fn transfer_1("));
+        assert!(rendered.contains("fn transfer_2("));
+        assert!(rendered.contains("struct Order"));
+        assert!(rendered.contains("-> Result<bool, CallError>"));
+        assert!(!rendered.contains(".expect("));
+    }
+}